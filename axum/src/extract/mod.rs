@@ -0,0 +1,21 @@
+//! Types and traits for extracting data from requests.
+//!
+//! See [`axum::extract`] for more general documentation.
+//!
+//! [`axum::extract`]: https://docs.rs/axum/latest/axum/extract/index.html
+
+mod query;
+
+pub use self::query::{
+    ConfiguredQuery, FromRejection, OptionalQuery, Query, QueryConfig, QueryConfiguredRejection,
+    QueryForm, WithRejection,
+};
+
+#[doc(no_inline)]
+pub use axum_core::extract::{FromRequest, FromRequestParts};
+
+/// Rejection response types.
+pub mod rejection {
+    #[doc(no_inline)]
+    pub use axum_core::extract::rejection::*;
+}
@@ -1,7 +1,8 @@
 use super::{rejection::*, FromRequestParts};
+use crate::response::{IntoResponse, Response};
 use http::request::Parts;
 use serde::de::DeserializeOwned;
-use std::{future::Future, ops::Deref};
+use std::{future::Future, marker::PhantomData, ops::Deref};
 
 /// Extractor that deserializes query strings into some type.
 ///
@@ -43,6 +44,10 @@ use std::{future::Future, ops::Deref};
 /// For handling values being empty vs missing see the (query-params-with-empty-strings)[example]
 /// example.
 ///
+/// To customize the rejection response, wrap `Query` in [`WithRejection`] — see
+/// [`ConfiguredQuery`] for a ready-made version that reads a [`QueryConfig`] out of the request's
+/// extensions.
+///
 /// [example]: https://github.com/tokio-rs/axum/blob/main/examples/query-params-with-empty-strings/src/main.rs
 #[cfg_attr(docsrs, doc(cfg(feature = "query")))]
 #[derive(Debug, Clone, Copy, Default)]
@@ -76,6 +81,379 @@ impl<T> Deref for Query<T> {
     }
 }
 
+/// Converts the rejection of some other extractor `Rejection` into `Self`, given the request
+/// [`Parts`] the extraction failed on.
+///
+/// Implemented by the error type `R` used with [`WithRejection<E, R>`]. Receiving `&Parts` lets
+/// an implementation pull request- or app-scoped configuration (such as a [`QueryConfig`] stored
+/// as an extension) when building its response, rather than being limited to a fixed, stateless
+/// conversion.
+#[cfg_attr(docsrs, doc(cfg(feature = "query")))]
+pub trait FromRejection<Rejection> {
+    /// Perform the conversion.
+    fn from_rejection(rejection: Rejection, parts: &Parts) -> Self;
+}
+
+/// Extractor that runs another extractor `E` and, if it fails, converts its rejection into `R`
+/// via [`FromRejection`] instead of using `E`'s own rejection type.
+///
+/// This lets an application customize the rejection response for an existing extractor — such as
+/// [`Query`] — without modifying the extractor itself. See [`ConfiguredQuery`] for a concrete
+/// example built this way.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::{rejection::QueryRejection, FromRejection, Query, WithRejection},
+///     http::request::Parts,
+///     response::{IntoResponse, Response},
+///     routing::get,
+///     Json, Router,
+/// };
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// struct ApiError(Response);
+///
+/// impl FromRejection<QueryRejection> for ApiError {
+///     fn from_rejection(rejection: QueryRejection, _parts: &Parts) -> Self {
+///         Self(Json(json!({ "error": rejection.to_string() })).into_response())
+///     }
+/// }
+///
+/// impl IntoResponse for ApiError {
+///     fn into_response(self) -> Response {
+///         self.0
+///     }
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Pagination {
+///     page: usize,
+/// }
+///
+/// async fn list_things(
+///     WithRejection(pagination, _): WithRejection<Query<Pagination>, ApiError>,
+/// ) {
+///     let pagination: Pagination = pagination.0;
+///
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/list_things", get(list_things));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "query")))]
+pub struct WithRejection<E, R>(pub E, pub PhantomData<R>);
+
+impl<E, R> std::fmt::Debug for WithRejection<E, R>
+where
+    E: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("WithRejection").field(&self.0).finish()
+    }
+}
+
+impl<E, R> Clone for WithRejection<E, R>
+where
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        WithRejection(self.0.clone(), PhantomData)
+    }
+}
+
+impl<E, R> Copy for WithRejection<E, R> where E: Copy {}
+
+impl<E, R, S> FromRequestParts<S> for WithRejection<E, R>
+where
+    E: FromRequestParts<S>,
+    R: FromRejection<E::Rejection> + IntoResponse,
+    S: Sync,
+{
+    type Rejection = R;
+
+    fn from_request_parts<'a>(
+        parts: &'a mut Parts,
+        state: &'a S,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send + 'a {
+        async move {
+            match E::from_request_parts(parts, state).await {
+                Ok(value) => Ok(WithRejection(value, PhantomData)),
+                Err(rejection) => Err(R::from_rejection(rejection, parts)),
+            }
+        }
+    }
+}
+
+impl<E, R> Deref for WithRejection<E, R> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Runtime configuration for the response returned when [`ConfiguredQuery`] fails to deserialize
+/// the query string.
+///
+/// Insert a `QueryConfig` into the request as an extension — e.g. via
+/// `.layer(axum::Extension(QueryConfig::new(...)))` — and handlers using [`ConfiguredQuery<T>`]
+/// will call it to build the rejection response instead of the default [`QueryRejection`] body.
+/// Unlike a type-level handler, the closure is an ordinary value, so it can capture whatever
+/// state the application needs (config flags, a localization table, etc.).
+#[cfg_attr(docsrs, doc(cfg(feature = "query")))]
+#[derive(Clone)]
+pub struct QueryConfig {
+    handler: std::sync::Arc<dyn Fn(&QueryRejection, &Parts) -> Response + Send + Sync>,
+}
+
+impl QueryConfig {
+    /// Create a `QueryConfig` that calls `handler` to build the response for a failed query
+    /// deserialization.
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&QueryRejection, &Parts) -> Response + Send + Sync + 'static,
+    {
+        Self {
+            handler: std::sync::Arc::new(handler),
+        }
+    }
+}
+
+impl std::fmt::Debug for QueryConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryConfig").finish_non_exhaustive()
+    }
+}
+
+/// Rejection returned by [`ConfiguredQuery<T>`].
+///
+/// Builds its response from the [`QueryConfig`] found in the request's extensions, if any;
+/// otherwise falls back to the default [`QueryRejection`] response.
+#[cfg_attr(docsrs, doc(cfg(feature = "query")))]
+pub struct QueryConfiguredRejection(Response);
+
+impl FromRejection<QueryRejection> for QueryConfiguredRejection {
+    fn from_rejection(rejection: QueryRejection, parts: &Parts) -> Self {
+        let response = match parts.extensions.get::<QueryConfig>() {
+            Some(config) => (config.handler)(&rejection, parts),
+            None => rejection.into_response(),
+        };
+        Self(response)
+    }
+}
+
+impl IntoResponse for QueryConfiguredRejection {
+    fn into_response(self) -> Response {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for QueryConfiguredRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("QueryConfiguredRejection")
+            .field(&self.0.status())
+            .finish()
+    }
+}
+
+/// [`Query`], wrapped so that a parse failure is turned into a response via the app's
+/// [`QueryConfig`] (read from the request's extensions) when one is present, falling back to the
+/// default [`QueryRejection`] response otherwise.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::{ConfiguredQuery, Query, QueryConfig, WithRejection},
+///     http::StatusCode,
+///     response::IntoResponse,
+///     routing::get,
+///     Extension, Router,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Pagination {
+///     page: usize,
+/// }
+///
+/// async fn list_things(
+///     WithRejection(Query(pagination), _): ConfiguredQuery<Pagination>,
+/// ) {
+///     let pagination: Pagination = pagination;
+///
+///     // ...
+/// }
+///
+/// let config = QueryConfig::new(|_error, _parts| StatusCode::IM_A_TEAPOT.into_response());
+///
+/// let app = Router::new()
+///     .route("/list_things", get(list_things))
+///     .layer(Extension(config));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+pub type ConfiguredQuery<T> = WithRejection<Query<T>, QueryConfiguredRejection>;
+
+/// Extractor that deserializes query strings into some type, treating the query string as an
+/// HTML form submission (`application/x-www-form-urlencoded`) rather than a flat map.
+///
+/// `T` is expected to implement [`serde::Deserialize`].
+///
+/// Unlike [`Query`], which deserializes with `serde_urlencoded` and therefore cannot collect a
+/// repeated key into a sequence, `QueryForm` deserializes with [`serde_html_form`], which
+/// models the query string as an ordered multimap. A field typed `Vec<T>` collects every
+/// occurrence of that key in the order they appear, a scalar field still uses the last
+/// occurrence, and `Option<Vec<T>>` distinguishes a key that's absent entirely from one that's
+/// present with no values. This matches how HTML forms submit repeated inputs such as checkboxes
+/// and multi-selects.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::QueryForm,
+///     routing::get,
+///     Router,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Filters {
+///     tag: Vec<String>,
+/// }
+///
+/// // This will parse query strings like `?tag=a&tag=b` into `Filters { tag: vec!["a", "b"] }`.
+/// async fn list_things(filters: QueryForm<Filters>) {
+///     let filters: Filters = filters.0;
+///
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/list_things", get(list_things));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+///
+/// If the query string cannot be parsed it will reject the request with a `400
+/// Bad Request` response.
+#[cfg_attr(docsrs, doc(cfg(feature = "query")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueryForm<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for QueryForm<T>
+where
+    T: DeserializeOwned + 'static,
+    S: Sync,
+{
+    type Rejection = QueryRejection;
+
+    fn from_request_parts<'a>(
+        parts: &'a mut Parts,
+        _state: &'a S,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send + 'a {
+        async move {
+            let query = parts.uri.query().unwrap_or_default();
+            let value = serde_html_form::from_str(query)
+                .map_err(FailedToDeserializeQueryString::__private_new)?;
+            Ok(QueryForm(value))
+        }
+    }
+}
+
+impl<T> Deref for QueryForm<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Extractor that deserializes query strings into some type, distinguishing a missing query
+/// string from one that's present but empty.
+///
+/// `T` is expected to implement [`serde::Deserialize`].
+///
+/// When the request URI has no `?` at all, `parts.uri.query()` returns [`None`] and
+/// `OptionalQuery` yields `OptionalQuery(None)` without attempting to deserialize anything. When
+/// a query string is present — including an empty one — it's deserialized into `T` as normal and
+/// malformed input is still rejected. This is useful for types whose fields are all optional,
+/// where [`Query`]'s `unwrap_or_default()` makes "no query string" indistinguishable from "an
+/// empty query string", even though callers may want to treat them differently (e.g. applying
+/// defaults only when no query was supplied at all).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::OptionalQuery,
+///     routing::get,
+///     Router,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Pagination {
+///     page: usize,
+///     per_page: usize,
+/// }
+///
+/// async fn list_things(pagination: OptionalQuery<Pagination>) {
+///     let pagination: Option<Pagination> = pagination.0;
+///
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/list_things", get(list_things));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+///
+/// If a query string is present but cannot be parsed it will reject the request with a `400
+/// Bad Request` response.
+#[cfg_attr(docsrs, doc(cfg(feature = "query")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptionalQuery<T>(pub Option<T>);
+
+impl<T, S> FromRequestParts<S> for OptionalQuery<T>
+where
+    T: DeserializeOwned + 'static,
+    S: Sync,
+{
+    type Rejection = QueryRejection;
+
+    fn from_request_parts<'a>(
+        parts: &'a mut Parts,
+        _state: &'a S,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send + 'a {
+        async move {
+            let Some(query) = parts.uri.query() else {
+                return Ok(OptionalQuery(None));
+            };
+            let value = serde_urlencoded::from_str(query)
+                .map_err(FailedToDeserializeQueryString::__private_new)?;
+            Ok(OptionalQuery(Some(value)))
+        }
+    }
+}
+
+impl<T> Deref for OptionalQuery<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{routing::get, test_helpers::TestClient, Router};
@@ -130,6 +508,127 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn test_query_form_repeated_keys() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Filters {
+            tag: Vec<String>,
+        }
+
+        let req = Request::builder()
+            .uri("http://example.com/test?tag=a&tag=b")
+            .body(())
+            .unwrap();
+        let filters = QueryForm::<Filters>::from_request(req, &())
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(
+            filters,
+            Filters {
+                tag: vec!["a".to_owned(), "b".to_owned()],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn configured_query_uses_default_when_unconfigured() {
+        #[derive(Deserialize)]
+        struct Params {
+            n: i32,
+        }
+
+        async fn handler(WithRejection(Query(params), _): ConfiguredQuery<Params>) -> String {
+            params.n.to_string()
+        }
+
+        let app = Router::new().route("/", get(handler));
+        let client = TestClient::new(app.clone());
+
+        let res = client.get("/?n=1").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await, "1");
+
+        let client = TestClient::new(app);
+        let res = client.get("/?n=hi").send().await;
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn configured_query_uses_query_config() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Params {
+            n: i32,
+        }
+
+        async fn handler(_: ConfiguredQuery<Params>) {}
+
+        let config = QueryConfig::new(|_error, _parts| StatusCode::IM_A_TEAPOT.into_response());
+        let app = Router::new()
+            .route("/", get(handler))
+            .layer(crate::Extension(config));
+        let client = TestClient::new(app);
+
+        let res = client.get("/?n=hi").send().await;
+        assert_eq!(res.status(), StatusCode::IM_A_TEAPOT);
+    }
+
+    #[tokio::test]
+    async fn test_optional_query_missing_vs_empty() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Pagination {
+            page: Option<u64>,
+        }
+
+        let req = Request::builder()
+            .uri("http://example.com/test")
+            .body(())
+            .unwrap();
+        let pagination = OptionalQuery::<Pagination>::from_request(req, &())
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(pagination, None);
+
+        let req = Request::builder()
+            .uri("http://example.com/test?")
+            .body(())
+            .unwrap();
+        let pagination = OptionalQuery::<Pagination>::from_request(req, &())
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(pagination, Some(Pagination { page: None }));
+
+        let req = Request::builder()
+            .uri("http://example.com/test?page=10")
+            .body(())
+            .unwrap();
+        let pagination = OptionalQuery::<Pagination>::from_request(req, &())
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(pagination, Some(Pagination { page: Some(10) }));
+    }
+
+    #[tokio::test]
+    async fn optional_query_rejects_malformed_query() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Params {
+            n: i32,
+        }
+
+        async fn handler(_: OptionalQuery<Params>) {}
+
+        let app = Router::new().route("/", get(handler));
+        let client = TestClient::new(app);
+
+        let res = client.get("/?n=hi").send().await;
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn correct_rejection_status_code() {
         #[derive(Deserialize)]